@@ -0,0 +1,93 @@
+//! Compares current [SplitOwned::split_owned] with the original
+//! `MaybeUninit` + swap implementation it replaced.
+//! 
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::mem::MaybeUninit;
+use std::time::{Duration, Instant};
+
+use split_owned::SplitOwned;
+
+/// Original implementation of [SplitOwned::split_owned].
+fn split_owned_old<T, const N: usize, const K: usize, const L: usize>(arr: [T; N]) -> ([T; K], [T; L]) {
+
+    const { assert!(N == K + L) };
+
+    let mut arr: [MaybeUninit<T>; N] = arr.map(|el| MaybeUninit::new(el));
+
+    let mut arr_k: [MaybeUninit<T>; K] = std::array::from_fn(|_| MaybeUninit::uninit());
+    let mut arr_l: [MaybeUninit<T>; L] = std::array::from_fn(|_| MaybeUninit::uninit());
+
+    for i in 0..K {
+        std::mem::swap(&mut arr_k[i], &mut arr[i]);
+    }
+    for i in 0..L {
+        std::mem::swap(&mut arr_l[i], &mut arr[i + K]);
+    }
+
+    let arr_k: [T; K] = arr_k.map(|el: MaybeUninit<T> | unsafe { el.assume_init() });
+    let arr_l: [T; L] = arr_l.map(|el: MaybeUninit<T> | unsafe { el.assume_init() });
+
+    (arr_k, arr_l)
+}
+
+/// Number of inputs prepared before each timed batch.
+const BATCH: usize = 64;
+
+/// Runs `f` on batches of clones of `input` for about `TIME` and returns average time per call.
+/// 
+/// Inputs are cloned before and outputs are dropped after the timed region,
+/// so only moving them in & out of pre-allocated vectors is measured along with `f`.
+fn bench<I: Clone, O>(input: &I, mut f: impl FnMut(I) -> O) -> Duration {
+    const TIME: Duration = Duration::from_millis(500);
+
+    let mut inputs: Vec<I> = Vec::with_capacity(BATCH);
+    let mut outputs: Vec<O> = Vec::with_capacity(BATCH);
+
+    let mut run_batch = || {
+        inputs.extend(std::iter::repeat_n(input, BATCH).cloned());
+
+        let start = Instant::now();
+        for input in inputs.drain(..) {
+            outputs.push(f(black_box(input)));
+        }
+        let elapsed = start.elapsed();
+
+        black_box(&mut outputs).clear();
+        elapsed
+    };
+
+    // Warm up
+    run_batch();
+
+    let mut total = Duration::ZERO;
+    let mut iters: u32 = 0;
+
+    while total < TIME {
+        total += run_batch();
+        iters += BATCH as u32;
+    }
+    total / iters
+}
+
+fn compare<T: Clone, const N: usize, const K: usize, const L: usize>(name: &str, arr: [T; N]) {
+
+    let old = bench(&arr, split_owned_old::<T, N, K, L>);
+    let new = bench(&arr, <[T; N]>::split_owned::<K, L>);
+
+    println!("{name:<32} old: {old:>12?}    new: {new:>12?}");
+}
+
+fn main() {
+    // Large N
+    compare::<u8, 4096, 1024, 3072>("[u8; 4096]", [7; 4096]);
+    compare::<u64, 16384, 8192, 8192>("[u64; 16384]", [7; 16384]);
+
+    // Large T
+    compare::<[u8; 1024], 64, 16, 48>("[[u8; 1024]; 64]", [[7; 1024]; 64]);
+    compare::<[u64; 512], 32, 31, 1>("[[u64; 512]; 32]", [[7; 512]; 32]);
+
+    // Non-Copy T
+    compare::<String, 256, 128, 128>("[String; 256]", std::array::from_fn(|n| n.to_string()));
+}
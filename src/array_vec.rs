@@ -0,0 +1,332 @@
+//! Fixed-capacity vector stored inline, used where the number of resulting elements is known only at runtime.
+
+use core::fmt;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::slice;
+
+use crate::read_owned;
+
+/// Vector with fixed capacity `CAP` which stores its elements inline, without allocation.
+/// ```
+/// use split_owned::ArrayVec;
+/// 
+/// let mut vec: ArrayVec<i32, 3> = ArrayVec::new();
+/// 
+/// vec.push(0);
+/// vec.push(1);
+/// 
+/// assert_eq!(vec, [0, 1]);
+/// assert_eq!(vec.try_push(2), Ok(()));
+/// assert_eq!(vec.try_push(3), Err(3));
+/// 
+/// assert_eq!(vec.into_array(), Ok([0, 1, 2]));
+/// ```
+pub struct ArrayVec<T, const CAP: usize> {
+    data: [MaybeUninit<T>; CAP],
+    len: usize,
+}
+
+impl<T, const CAP: usize> ArrayVec<T, CAP> {
+
+    /// Creates new empty vector.
+    pub const fn new() -> Self {
+        Self { data: [const { MaybeUninit::uninit() }; CAP], len: 0 }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// Appends element to the back of the vector.
+    /// 
+    /// # Panics
+    /// Panics if the vector is full.
+    pub fn push(&mut self, el: T) {
+        assert!(self.len < CAP, "ArrayVec is full");
+
+        self.data[self.len].write(el);
+        self.len += 1;
+    }
+
+    /// Appends element to the back of the vector or returns it back if the vector is full.
+    pub fn try_push(&mut self, el: T) -> Result<(), T> {
+        if self.len == CAP {
+            return Err(el);
+        }
+        self.data[self.len].write(el);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes last element from the vector and returns it.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        // SAFETY: Element at `len` was initialized and is no longer owned by the vector
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+
+    /// Drops all elements of the vector.
+    pub fn clear(&mut self) {
+        let elements: *mut [T] = self.as_mut_slice();
+
+        // Elements are forgotten before dropping, so a panic in `drop` can only leak them
+        self.len = 0;
+
+        // SAFETY: Elements are initialized and no longer owned by the vector
+        unsafe { ptr::drop_in_place(elements) };
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: Elements 0..len are initialized
+        unsafe { slice::from_raw_parts(self.data.as_ptr().cast(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: Elements 0..len are initialized
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Converts full vector into an array or returns it back if it's not full.
+    pub fn into_array(self) -> Result<[T; CAP], Self> {
+        if self.len != CAP {
+            return Err(self);
+        }
+        let vec = ManuallyDrop::new(self);
+
+        // SAFETY: All CAP elements are initialized, vector is never dropped
+        Ok(unsafe { read_owned(vec.data.as_ptr().cast()) })
+    }
+}
+
+impl<T, const CAP: usize> Drop for ArrayVec<T, CAP> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const CAP: usize> Default for ArrayVec<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAP: usize> Deref for ArrayVec<T, CAP> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> DerefMut for ArrayVec<T, CAP> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const CAP: usize> Clone for ArrayVec<T, CAP> {
+    fn clone(&self) -> Self {
+        let mut vec = Self::new();
+        for el in self.iter() {
+            vec.push(el.clone());
+        }
+        vec
+    }
+}
+
+impl<T: fmt::Debug, const CAP: usize> fmt::Debug for ArrayVec<T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq, const CAP: usize> PartialEq for ArrayVec<T, CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const CAP: usize> Eq for ArrayVec<T, CAP> {}
+
+impl<T: PartialEq<U>, U, const CAP: usize, const M: usize> PartialEq<[U; M]> for ArrayVec<T, CAP> {
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, const CAP: usize> IntoIterator for ArrayVec<T, CAP> {
+    type Item = T;
+    type IntoIter = IntoIter<T, CAP>;
+
+    fn into_iter(self) -> IntoIter<T, CAP> {
+        let vec = ManuallyDrop::new(self);
+
+        // SAFETY: Elements are moved into the iterator, vector is never dropped
+        IntoIter { data: unsafe { ptr::read(&vec.data) }, start: 0, end: vec.len }
+    }
+}
+
+impl<'a, T, const CAP: usize> IntoIterator for &'a ArrayVec<T, CAP> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAP: usize> IntoIterator for &'a mut ArrayVec<T, CAP> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Owning iterator over elements of [ArrayVec].
+pub struct IntoIter<T, const CAP: usize> {
+    data: [MaybeUninit<T>; CAP],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const CAP: usize> IntoIter<T, CAP> {
+
+    /// Remaining elements of the iterator.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: Elements start..end are initialized
+        unsafe { slice::from_raw_parts(self.data.as_ptr().add(self.start).cast(), self.end - self.start) }
+    }
+}
+
+impl<T, const CAP: usize> Iterator for IntoIter<T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+
+        // SAFETY: Element at `start - 1` was initialized and is no longer owned by the iterator
+        Some(unsafe { self.data[self.start - 1].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const CAP: usize> DoubleEndedIterator for IntoIter<T, CAP> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+
+        // SAFETY: Element at `end` was initialized and is no longer owned by the iterator
+        Some(unsafe { self.data[self.end].assume_init_read() })
+    }
+}
+
+impl<T, const CAP: usize> ExactSizeIterator for IntoIter<T, CAP> {}
+
+impl<T, const CAP: usize> Drop for IntoIter<T, CAP> {
+    fn drop(&mut self) {
+        let elements: *mut [T] = ptr::slice_from_raw_parts_mut(
+            self.data.as_mut_ptr().wrapping_add(self.start).cast(), self.end - self.start
+        );
+
+        // Elements are forgotten before dropping, so a panic in `drop` can only leak them
+        self.start = self.end;
+
+        // SAFETY: Elements are initialized and no longer owned by the iterator
+        unsafe { ptr::drop_in_place(elements) };
+    }
+}
+
+impl<T: fmt::Debug, const CAP: usize> fmt::Debug for IntoIter<T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::rc::Rc;
+
+    #[test]
+    fn push_pop() {
+        let mut vec: ArrayVec<f64, 3> = ArrayVec::new();
+        assert!(vec.is_empty());
+
+        vec.push(0.);
+        vec.push(1.);
+        vec.push(2.);
+        assert!(vec.is_full());
+        assert_eq!(vec.try_push(3.), Err(3.));
+
+        assert_eq!(vec.pop(), Some(2.));
+        assert_eq!(vec, [0., 1.]);
+        assert_eq!(vec.len(), 2);
+
+        let vec = vec.into_array().unwrap_err();
+        assert_eq!(vec, [0., 1.]);
+    }
+
+    #[test]
+    #[should_panic = "ArrayVec is full"]
+    fn push_full() {
+        let mut vec: ArrayVec<f64, 0> = ArrayVec::new();
+        vec.push(0.);
+    }
+
+    #[test]
+    fn drops_once() {
+        let rc = Rc::new(());
+
+        let mut vec: ArrayVec<Rc<()>, 5> = ArrayVec::new();
+        for _ in 0..4 {
+            vec.push(Rc::clone(&rc));
+        }
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        let mut iter = vec.clone().into_iter();
+        assert_eq!(Rc::strong_count(&rc), 9);
+
+        drop(iter.next());
+        drop(iter.next_back());
+        assert_eq!(iter.len(), 2);
+        assert_eq!(Rc::strong_count(&rc), 7);
+
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        vec.clear();
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
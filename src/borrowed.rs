@@ -0,0 +1,112 @@
+use crate::const_assert;
+
+/// Extention trait which provides [SplitRef::split_ref] function,
+/// borrowed counterpart of [SplitOwned::split_owned](crate::SplitOwned::split_owned).
+pub trait SplitRef<T> {
+    fn split_ref<const K: usize, const L: usize>(&self) -> (&[T; K], &[T; L]);
+}
+
+/// Extention trait which provides [SplitMut::split_ref_mut] function,
+/// mutably borrowed counterpart of [SplitOwned::split_owned](crate::SplitOwned::split_owned).
+pub trait SplitMut<T> {
+    fn split_ref_mut<const K: usize, const L: usize>(&mut self) -> (&mut [T; K], &mut [T; L]);
+}
+
+impl<T, const N: usize> SplitRef<T> for [T; N] {
+
+    /// Common usage:
+    /// ```
+    /// use split_owned::SplitRef;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (arr1, arr2) = arr.split_ref::<3, 4>();
+    /// 
+    /// assert_eq!(arr1, &[0, 1, 2]);
+    /// assert_eq!(arr2, &[3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitRef;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to sum of lengths of resulting arrays N == K + L
+    /// let (arr1, arr2) = arr.split_ref::<2, 4>();
+    /// ```
+    fn split_ref<const K: usize, const L: usize>(&self) -> (&[T; K], &[T; L]) {
+
+        const { const_assert(N == K + L, 
+            "Length of original array has to be equal to sum of lengths of resulting arrays N == K + L"
+        )};
+
+        let ptr: *const T = self.as_ptr();
+
+        // SAFETY: Ranges 0..K and K..N are within the array, `[T; K]` has the same alignment as `T`
+        unsafe { (&*ptr.cast::<[T; K]>(), &*ptr.add(K).cast::<[T; L]>()) }
+    }
+}
+
+impl<T, const N: usize> SplitMut<T> for [T; N] {
+
+    /// Common usage:
+    /// ```
+    /// use split_owned::SplitMut;
+    /// 
+    /// let mut arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (arr1, arr2) = arr.split_ref_mut::<3, 4>();
+    /// 
+    /// arr1[0] = 10;
+    /// arr2[0] = 30;
+    /// 
+    /// assert_eq!(arr, [10, 1, 2, 30, 4, 5, 6]);
+    /// ```
+    fn split_ref_mut<const K: usize, const L: usize>(&mut self) -> (&mut [T; K], &mut [T; L]) {
+
+        const { const_assert(N == K + L, 
+            "Length of original array has to be equal to sum of lengths of resulting arrays N == K + L"
+        )};
+
+        let ptr: *mut T = self.as_mut_ptr();
+
+        // SAFETY: Ranges 0..K and K..N are within the array and don't overlap,
+        // `[T; K]` has the same alignment as `T`
+        unsafe { (&mut *ptr.cast::<[T; K]>(), &mut *ptr.add(K).cast::<[T; L]>()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn split_ref_easy() {
+        let arr: [f64; 6] = std::array::from_fn(|n| n as f64);
+
+        let (arr1, arr2) = arr.split_ref::<2, 4>();
+        assert_eq!(arr1, &[0., 1.]);
+        assert_eq!(arr2, &[2., 3., 4., 5.]);
+
+        let arr_ref: &[f64; 6] = &arr;
+        let (arr1, arr2): (&[f64; 6], &[f64; 0]) = arr_ref.split_ref();
+        assert_eq!(arr1, &arr);
+        assert_eq!(arr2, &[]);
+    }
+
+    #[test]
+    fn split_ref_mut_swap() {
+        let mut arr: [f64; 6] = std::array::from_fn(|n| n as f64);
+
+        let (arr1, arr2) = arr.split_ref_mut::<3, 3>();
+        std::mem::swap(arr1, arr2);
+
+        assert_eq!(arr, [3., 4., 5., 0., 1., 2.]);
+
+        let arr_mut: &mut [f64; 6] = &mut arr;
+        let (arr1, _) = arr_mut.split_ref_mut::<0, 6>();
+        assert_eq!(arr1, &mut []);
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::alloc::{Layout, dealloc, handle_alloc_error, realloc};
+use alloc::boxed::Box;
+use core::mem;
+use core::ptr::{self, NonNull};
+
+use crate::const_assert;
+
+/// Extention trait which provides [SplitBoxed::split_boxed] function.
+/// 
+/// Unlike [SplitOwned::split_owned](crate::SplitOwned::split_owned) the array is never moved through the stack,
+/// so it can be used with arrays too large to fit there.
+pub trait SplitBoxed<T> {
+    fn split_boxed<const K: usize, const L: usize>(self) -> (Box<[T; K]>, Box<[T; L]>);
+}
+
+impl<T, const N: usize> SplitBoxed<T> for Box<[T; N]> {
+
+    /// Original allocation is reused for the left array, right array is moved into new allocation.
+    /// ```
+    /// use split_owned::SplitBoxed;
+    /// 
+    /// let arr: Box<[i32; 7]> = Box::new([0, 1, 2, 3, 4, 5, 6]);
+    /// 
+    /// let (arr1, arr2) = arr.split_boxed::<3, 4>();
+    /// 
+    /// assert_eq!(*arr1, [0, 1, 2]);
+    /// assert_eq!(*arr2, [3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitBoxed;
+    /// 
+    /// let arr: Box<[i32; 7]> = Box::new([0, 1, 2, 3, 4, 5, 6]);
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to sum of lengths of resulting arrays N == K + L
+    /// let (arr1, arr2) = arr.split_boxed::<2, 4>();
+    /// ```
+    fn split_boxed<const K: usize, const L: usize>(self) -> (Box<[T; K]>, Box<[T; L]>) {
+
+        const { const_assert(N == K + L, 
+            "Length of original array has to be equal to sum of lengths of resulting arrays N == K + L"
+        )};
+
+        // Allocate before taking the array apart so nothing leaks if allocation panics
+        let mut arr_l: Box<mem::MaybeUninit<[T; L]>> = Box::new_uninit();
+
+        let arr: *mut T = Box::into_raw(self).cast();
+
+        // SAFETY: Elements K..N are moved into new allocation exactly once,
+        // elements 0..K stay in place and are owned by the shrunk allocation
+        unsafe {
+            ptr::copy_nonoverlapping(arr.add(K), arr_l.as_mut_ptr().cast::<T>(), L);
+
+            let arr_k: *mut [T; K] = shrink::<T, N, K>(arr).cast();
+
+            (Box::from_raw(arr_k), arr_l.assume_init())
+        }
+    }
+}
+
+/// Shrinks allocation of `[T; N]` made by [Box] to fit `[T; K]`.
+/// 
+/// # Safety
+/// `arr` has to be allocated by [Box] with layout of `[T; N]` and `K <= N`.
+/// Elements `K..N` are not dropped.
+unsafe fn shrink<T, const N: usize, const K: usize>(arr: *mut T) -> *mut T {
+
+    let old_layout = Layout::new::<[T; N]>();
+    let new_layout = Layout::new::<[T; K]>();
+
+    if old_layout.size() == 0 {
+        // Nothing was allocated, pointer is dangling & well-aligned already
+        return arr;
+    }
+    if new_layout.size() == 0 {
+        // SAFETY: `arr` was allocated with `old_layout`
+        unsafe { dealloc(arr.cast(), old_layout) };
+        return NonNull::dangling().as_ptr();
+    }
+
+    // SAFETY: `arr` was allocated with `old_layout`, new size is non-zero
+    // and does not overflow because it is not greater than the old one
+    let new_arr = unsafe { realloc(arr.cast(), old_layout, new_layout.size()) };
+
+    if new_arr.is_null() {
+        handle_alloc_error(new_layout);
+    }
+    new_arr.cast()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::rc::Rc;
+    use std::string::String;
+
+    #[test]
+    fn split_boxed_large() {
+        const N: usize = 1 << 20;
+
+        let arr: Box<[u8; N]> = (0..N).map(|n| n as u8).collect::<Box<[u8]>>().try_into().unwrap();
+
+        let (arr1, arr2) = arr.split_boxed::<{ N / 4 }, { N - N / 4 }>();
+
+        assert!(arr1.iter().enumerate().all(|(n, el)| *el == n as u8));
+        assert!(arr2.iter().enumerate().all(|(n, el)| *el == (n + N / 4) as u8));
+    }
+
+    #[test]
+    fn split_boxed_zero() {
+        let arr: Box<[String; 3]> = Box::new(["a", "b", "c"].map(String::from));
+
+        let (arr1, arr2) = arr.split_boxed::<0, 3>();
+        assert_eq!(*arr1, <[String; 0]>::default());
+        assert_eq!(*arr2, ["a", "b", "c"]);
+
+        let (arr1, arr2) = arr2.split_boxed::<3, 0>();
+        assert_eq!(*arr1, ["a", "b", "c"]);
+        assert_eq!(*arr2, <[String; 0]>::default());
+
+        let (arr1, arr2) = Box::new([(); 5]).split_boxed::<2, 3>();
+        assert_eq!(*arr1, [(); 2]);
+        assert_eq!(*arr2, [(); 3]);
+    }
+
+    #[test]
+    fn split_boxed_drops_once() {
+        let rc = Rc::new(());
+
+        let arr: Box<[Rc<()>; 5]> = Box::new(std::array::from_fn(|_| Rc::clone(&rc)));
+
+        let (arr1, arr2) = arr.split_boxed::<2, 3>();
+        assert_eq!(Rc::strong_count(&rc), 6);
+
+        drop(arr1);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        drop(arr2);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,91 @@
+use core::fmt;
+
+/// Error returned when length of a container known only at runtime does not match the expected one.
+/// 
+/// Holds original container, so nothing is lost on failure.
+pub struct LengthMismatch<C> {
+    expected: usize,
+    actual: usize,
+    container: C,
+}
+
+impl<C> LengthMismatch<C> {
+
+    pub fn new(expected: usize, actual: usize, container: C) -> Self {
+        Self { expected, actual, container }
+    }
+
+    /// Length the container was expected to have.
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Length the container actually had.
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+
+    /// Returns original container.
+    pub fn into_inner(self) -> C {
+        self.container
+    }
+}
+
+impl<C> fmt::Debug for LengthMismatch<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LengthMismatch")
+            .field("expected", &self.expected)
+            .field("actual", &self.actual)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C> fmt::Display for LengthMismatch<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected length {}, found {}", self.expected, self.actual)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> std::error::Error for LengthMismatch<C> {}
+
+/// Error returned by [CollectSplit::collect_split](crate::CollectSplit::collect_split)
+/// when iterator yields wrong number of items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectSplitError {
+    expected: usize,
+    seen: usize,
+}
+
+impl CollectSplitError {
+
+    pub fn new(expected: usize, seen: usize) -> Self {
+        Self { expected, seen }
+    }
+
+    /// Number of items iterator was expected to yield.
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Number of items iterator yielded before collecting stopped.
+    /// 
+    /// Iterator is not consumed further than one item past the expected number,
+    /// so for longer iterators this is `expected + 1`.
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+}
+
+impl fmt::Display for CollectSplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.seen > self.expected {
+            write!(f, "expected {} items, iterator yielded more", self.expected)
+        } else {
+            write!(f, "expected {} items, iterator yielded {}", self.expected, self.seen)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CollectSplitError {}
@@ -0,0 +1,262 @@
+use core::iter::FusedIterator;
+
+use crate::{ArrayVec, CollectSplitError, const_assert};
+
+/// Extention trait which provides [IntoOwnedChunks::owned_chunks] function
+/// for sources which length is not known at compile time.
+/// 
+/// Implemented for every [IntoIterator].
+pub trait IntoOwnedChunks: IntoIterator + Sized {
+
+    /// Returns iterator over owned chunks of length `K`.
+    /// Elements which don't fill the last chunk are available through [OwnedChunks::into_remainder].
+    /// ```
+    /// use split_owned::IntoOwnedChunks;
+    /// 
+    /// let mut chunks = vec![0, 1, 2, 3, 4, 5, 6].owned_chunks::<3>();
+    /// 
+    /// assert_eq!(chunks.next(), Some([0, 1, 2]));
+    /// assert_eq!(chunks.next(), Some([3, 4, 5]));
+    /// assert_eq!(chunks.next(), None);
+    /// 
+    /// assert_eq!(chunks.into_remainder().unwrap(), [6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::IntoOwnedChunks;
+    /// 
+    /// // Compile error: 
+    /// // Length of chunks has to be greater than zero K > 0
+    /// let chunks = vec![0, 1, 2, 3, 4, 5, 6].owned_chunks::<0>();
+    /// ```
+    fn owned_chunks<const K: usize>(self) -> OwnedChunks<Self::IntoIter, K> {
+
+        const { const_assert(K > 0, 
+            "Length of chunks has to be greater than zero K > 0"
+        )};
+
+        OwnedChunks { iter: self.into_iter(), remainder: None }
+    }
+}
+
+impl<I: IntoIterator> IntoOwnedChunks for I {}
+
+/// Iterator over owned chunks of length `K`, created by [IntoOwnedChunks::owned_chunks].
+/// 
+/// Elements of partially filled chunk are dropped if underlying iterator panics.
+#[derive(Debug, Clone)]
+pub struct OwnedChunks<I: Iterator, const K: usize> {
+    iter: I,
+    remainder: Option<ArrayVec<I::Item, K>>,
+}
+
+impl<I: Iterator, const K: usize> OwnedChunks<I, K> {
+
+    /// Elements left after the last full chunk.
+    /// Empty until underlying iterator is exhausted.
+    pub fn remainder(&self) -> &[I::Item] {
+        self.remainder.as_deref().unwrap_or(&[])
+    }
+
+    /// Returns elements left after the last full chunk
+    /// or `None` if underlying iterator is not exhausted yet.
+    pub fn into_remainder(self) -> Option<ArrayVec<I::Item, K>> {
+        self.remainder
+    }
+}
+
+impl<I: Iterator, const K: usize> Iterator for OwnedChunks<I, K> {
+    type Item = [I::Item; K];
+
+    fn next(&mut self) -> Option<[I::Item; K]> {
+        if self.remainder.is_some() {
+            return None;
+        }
+
+        let mut chunk: ArrayVec<I::Item, K> = ArrayVec::new();
+
+        while !chunk.is_full() {
+            match self.iter.next() {
+                Some(el) => chunk.push(el),
+                None => {
+                    self.remainder = Some(chunk);
+                    return None;
+                }
+            }
+        }
+        chunk.into_array().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remainder.is_some() {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (lower / K, upper.map(|upper| upper / K))
+    }
+}
+
+impl<I: Iterator, const K: usize> FusedIterator for OwnedChunks<I, K> {}
+
+/// Extention trait which provides [CollectSplit::collect_split] function.
+/// 
+/// Implemented for every [IntoIterator].
+pub trait CollectSplit<T>: IntoIterator<Item = T> + Sized {
+
+    /// Collects exactly `K + L` items into 2 owned arrays.
+    /// 
+    /// Returns error if iterator yields less or more items. Collected items are dropped in that case.
+    /// ```
+    /// use split_owned::CollectSplit;
+    /// 
+    /// let (head, body): ([i32; 2], [i32; 5]) = (0..7).collect_split().unwrap();
+    /// 
+    /// assert_eq!(head, [0, 1]);
+    /// assert_eq!(body, [2, 3, 4, 5, 6]);
+    /// 
+    /// let err = (0..5).collect_split::<2, 5>().unwrap_err();
+    /// 
+    /// assert_eq!((err.expected(), err.seen()), (7, 5));
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::CollectSplit;
+    /// 
+    /// // Compile error: 
+    /// // Sum of lengths of resulting arrays has to fit in usize K + L <= usize::MAX
+    /// let result = core::iter::empty::<()>().collect_split::<{ usize::MAX }, 1>();
+    /// ```
+    fn collect_split<const K: usize, const L: usize>(self) -> Result<([T; K], [T; L]), CollectSplitError> {
+
+        const { const_assert(K <= usize::MAX - L, 
+            "Sum of lengths of resulting arrays has to fit in usize K + L <= usize::MAX"
+        )};
+
+        let mut iter = self.into_iter();
+
+        let mut arr_k: ArrayVec<T, K> = ArrayVec::new();
+        let mut arr_l: ArrayVec<T, L> = ArrayVec::new();
+
+        fill(&mut iter, &mut arr_k);
+        if arr_k.is_full() {
+            fill(&mut iter, &mut arr_l);
+        }
+        // Iterator is checked for one more item only if it didn't end earlier
+        let extra: bool = arr_l.is_full() && iter.next().is_some();
+
+        let seen = arr_k.len() + arr_l.len() + extra as usize;
+        if seen != K + L {
+            return Err(CollectSplitError::new(K + L, seen));
+        }
+
+        match (arr_k.into_array(), arr_l.into_array()) {
+            (Ok(arr_k), Ok(arr_l)) => Ok((arr_k, arr_l)),
+            _ => unreachable!("both arrays are full"),
+        }
+    }
+}
+
+impl<T, I: IntoIterator<Item = T>> CollectSplit<T> for I {}
+
+/// Moves items from iterator into the vector until it's full or iterator ends.
+fn fill<I: Iterator, const C: usize>(iter: &mut I, vec: &mut ArrayVec<I::Item, C>) {
+    while !vec.is_full() {
+        match iter.next() {
+            Some(el) => vec.push(el),
+            None => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn owned_chunks_non_clone() {
+        let mut chunks = (0..8).map(|n| n.to_string()).owned_chunks::<3>();
+
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+        assert_eq!(chunks.next(), Some(["0", "1", "2"].map(String::from)));
+        assert_eq!(chunks.remainder(), <[String; 0]>::default());
+        assert_eq!(chunks.next(), Some(["3", "4", "5"].map(String::from)));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), ["6", "7"]);
+        assert_eq!(chunks.into_remainder().unwrap(), ["6", "7"]);
+
+        let mut chunks = [0., 1., 2., 3.].owned_chunks::<2>();
+
+        assert_eq!(chunks.by_ref().count(), 2);
+        assert!(chunks.into_remainder().unwrap().is_empty());
+        assert!([0.; 4].owned_chunks::<2>().into_remainder().is_none());
+    }
+
+    #[test]
+    fn owned_chunks_panic_drops_partial_chunk() {
+        let rc = Rc::new(());
+
+        let iter = (0..7).map(|n| {
+            if n == 5 {
+                panic!("iterator panicked");
+            }
+            Rc::clone(&rc)
+        });
+        let mut chunks = iter.owned_chunks::<3>();
+
+        let chunk = chunks.next().unwrap();
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| chunks.next()));
+        assert!(result.is_err());
+
+        drop(chunk);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn collect_split_exact() {
+        let (head, body): ([String; 1], [String; 2]) = ["a", "b", "c"].map(String::from).collect_split().unwrap();
+
+        assert_eq!(head, ["a"]);
+        assert_eq!(body, ["b", "c"]);
+
+        let (head, body) = std::iter::empty::<f64>().collect_split::<0, 0>().unwrap();
+        assert_eq!(head, []);
+        assert_eq!(body, []);
+    }
+
+    #[test]
+    fn collect_split_wrong_length_drops_items() {
+        let rc = Rc::new(());
+
+        let err = std::iter::repeat_with(|| Rc::clone(&rc)).take(2).collect_split::<3, 1>().unwrap_err();
+        assert_eq!(err, CollectSplitError::new(4, 2));
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let err = std::iter::repeat_with(|| Rc::clone(&rc)).take(4).collect_split::<1, 2>().unwrap_err();
+        assert_eq!(err, CollectSplitError::new(3, 4));
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let err = std::iter::repeat_with(|| Rc::clone(&rc)).collect_split::<2, 2>().unwrap_err();
+        assert_eq!(err.seen(), 5);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collect_split_error() {
+        fn parse() -> Result<([u8; 1], [u8; 3]), std::boxed::Box<dyn std::error::Error>> {
+            let (head, body) = "abc".bytes().collect_split()?;
+            Ok((head, body))
+        }
+
+        assert_eq!(parse().unwrap_err().to_string(), "expected 4 items, iterator yielded 3");
+        assert_eq!(CollectSplitError::new(4, 5).to_string(), "expected 4 items, iterator yielded more");
+    }
+}
@@ -0,0 +1,341 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+use crate::{const_assert, write_owned};
+
+/// Extention trait which provides [JoinOwned::join_owned] function,
+/// inverse of [SplitOwned::split_owned](crate::SplitOwned::split_owned).
+/// 
+/// Implemented for tuples of 2, 3 and 4 arrays.
+pub trait JoinOwned<T> {
+    fn join_owned<const N: usize>(self) -> [T; N];
+}
+
+impl<T, const K: usize, const L: usize> JoinOwned<T> for ([T; K], [T; L]) {
+
+    /// Common usage:
+    /// ```
+    /// use split_owned::JoinOwned;
+    /// 
+    /// let arr1: [i32; 3] = [0, 1, 2];
+    /// let arr2: [i32; 4] = [3, 4, 5, 6];
+    /// 
+    /// let arr = (arr1, arr2).join_owned::<7>();
+    /// 
+    /// assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::JoinOwned;
+    /// 
+    /// let arr1: [i32; 3] = [0, 1, 2];
+    /// let arr2: [i32; 4] = [3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of resulting array has to be equal to sum of lengths of original arrays N == K + L
+    /// let arr = (arr1, arr2).join_owned::<6>();
+    /// ```
+    fn join_owned<const N: usize>(self) -> [T; N] {
+
+        const { const_assert(N == K + L, 
+            "Length of resulting array has to be equal to sum of lengths of original arrays N == K + L"
+        )};
+
+        let (arr_k, arr_l) = self;
+
+        let mut arr: MaybeUninit<[T; N]> = MaybeUninit::uninit();
+        let ptr: *mut T = arr.as_mut_ptr().cast();
+
+        // SAFETY: Ranges 0..K and K..N are disjoint and cover the whole array
+        unsafe {
+            write_owned(ptr, arr_k);
+            write_owned(ptr.add(K), arr_l);
+
+            arr.assume_init()
+        }
+    }
+}
+
+impl<T, const A: usize, const B: usize, const C: usize> JoinOwned<T> for ([T; A], [T; B], [T; C]) {
+
+    /// ```
+    /// use split_owned::JoinOwned;
+    /// 
+    /// let header: [u8; 2] = [0xAA, 0x03];
+    /// let payload: [u8; 4] = [1, 2, 3, 4];
+    /// let trailer: [u8; 2] = [0xFF, 0xFF];
+    /// 
+    /// let packet: [u8; 8] = (header, payload, trailer).join_owned();
+    /// 
+    /// assert_eq!(packet, [0xAA, 0x03, 1, 2, 3, 4, 0xFF, 0xFF]);
+    /// ```
+    fn join_owned<const N: usize>(self) -> [T; N] {
+
+        const { const_assert(N == A + B + C, 
+            "Length of resulting array has to be equal to sum of lengths of original arrays N == A + B + C"
+        )};
+
+        let (arr_a, arr_b, arr_c) = self;
+
+        let mut arr: MaybeUninit<[T; N]> = MaybeUninit::uninit();
+        let ptr: *mut T = arr.as_mut_ptr().cast();
+
+        // SAFETY: Ranges are disjoint and cover the whole array
+        unsafe {
+            write_owned(ptr, arr_a);
+            write_owned(ptr.add(A), arr_b);
+            write_owned(ptr.add(A + B), arr_c);
+
+            arr.assume_init()
+        }
+    }
+}
+
+impl<T, const A: usize, const B: usize, const C: usize, const D: usize> JoinOwned<T> for ([T; A], [T; B], [T; C], [T; D]) {
+
+    /// ```
+    /// use split_owned::JoinOwned;
+    /// 
+    /// let arr = ([0], [1, 2], [3, 4, 5], [6]).join_owned::<7>();
+    /// 
+    /// assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn join_owned<const N: usize>(self) -> [T; N] {
+
+        const { const_assert(N == A + B + C + D, 
+            "Length of resulting array has to be equal to sum of lengths of original arrays N == A + B + C + D"
+        )};
+
+        let (arr_a, arr_b, arr_c, arr_d) = self;
+
+        let mut arr: MaybeUninit<[T; N]> = MaybeUninit::uninit();
+        let ptr: *mut T = arr.as_mut_ptr().cast();
+
+        // SAFETY: Ranges are disjoint and cover the whole array
+        unsafe {
+            write_owned(ptr, arr_a);
+            write_owned(ptr.add(A), arr_b);
+            write_owned(ptr.add(A + B), arr_c);
+            write_owned(ptr.add(A + B + C), arr_d);
+
+            arr.assume_init()
+        }
+    }
+}
+
+/// Extention trait which provides [InterleaveOwned::interleave_owned] function,
+/// inverse of [SplitOwned::deinterleave_owned](crate::SplitOwned::deinterleave_owned).
+/// 
+/// Implemented for pair of arrays and array of `S` lanes.
+pub trait InterleaveOwned<T> {
+    fn interleave_owned<const N: usize>(self) -> [T; N];
+}
+
+impl<T, const F: usize> InterleaveOwned<T> for ([T; F], [T; F]) {
+
+    /// ```
+    /// use split_owned::InterleaveOwned;
+    /// 
+    /// let left: [f32; 3] = [0.1, 0.2, 0.3];
+    /// let right: [f32; 3] = [-0.1, -0.2, -0.3];
+    /// 
+    /// let stereo: [f32; 6] = (left, right).interleave_owned();
+    /// 
+    /// assert_eq!(stereo, [0.1, -0.1, 0.2, -0.2, 0.3, -0.3]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::InterleaveOwned;
+    /// 
+    /// // Compile error: 
+    /// // Length of resulting array has to be equal to product of lane length and stride N == F * S
+    /// let arr = ([0, 2, 4], [1, 3, 5]).interleave_owned::<5>();
+    /// ```
+    fn interleave_owned<const N: usize>(self) -> [T; N] {
+        let (even, odd) = self;
+        [even, odd].interleave_owned()
+    }
+}
+
+impl<T, const F: usize, const S: usize> InterleaveOwned<T> for [[T; F]; S] {
+
+    /// Element `i` of resulting array is taken from lane `i % S`.
+    /// ```
+    /// use split_owned::InterleaveOwned;
+    /// 
+    /// let r: [u8; 3] = [255, 0, 0];
+    /// let g: [u8; 3] = [0, 255, 0];
+    /// let b: [u8; 3] = [0, 0, 255];
+    /// 
+    /// let rgb: [u8; 9] = [r, g, b].interleave_owned();
+    /// 
+    /// assert_eq!(rgb, [255, 0, 0, 0, 255, 0, 0, 0, 255]);
+    /// ```
+    fn interleave_owned<const N: usize>(self) -> [T; N] {
+
+        const { const_assert(N == F * S, 
+            "Length of resulting array has to be equal to product of lane length and stride N == F * S"
+        )};
+
+        let lanes = ManuallyDrop::new(self);
+        let ptr: *const T = lanes.as_ptr().cast();
+
+        // SAFETY: Element `i / S` of lane `i % S` is read exactly once for every i < N == F * S
+        core::array::from_fn(|i| unsafe { ptr.add((i % S) * F + i / S).read() })
+    }
+}
+
+/// Extention trait which provides [InsertOwned::insert_owned] function,
+/// inverse of [SplitOwned::extract_owned](crate::SplitOwned::extract_owned).
+pub trait InsertOwned<T> {
+    fn insert_owned<const I: usize, const M: usize>(self, el: T) -> [T; M];
+}
+
+impl<T, const N: usize> InsertOwned<T> for [T; N] {
+
+    /// Moves element into array at index `I`, shifting all elements after it to the right.
+    /// ```
+    /// use split_owned::InsertOwned;
+    /// 
+    /// let arr: [i32; 6] = [0, 1, 3, 4, 5, 6];
+    /// 
+    /// let arr = arr.insert_owned::<2, 7>(2);
+    /// 
+    /// assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::InsertOwned;
+    /// 
+    /// let arr: [i32; 6] = [0, 1, 2, 3, 4, 5];
+    /// 
+    /// // Compile error: 
+    /// // Index of inserted element has to be within the resulting array I <= N
+    /// let arr = arr.insert_owned::<7, 7>(6);
+    /// ```
+    fn insert_owned<const I: usize, const M: usize>(self, el: T) -> [T; M] {
+
+        const { const_assert(I <= N, 
+            "Index of inserted element has to be within the resulting array I <= N"
+        )};
+        const { const_assert(M == N + 1, 
+            "Length of resulting array has to be one more than length of original array M == N + 1"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        let mut res: MaybeUninit<[T; M]> = MaybeUninit::uninit();
+        let res_ptr: *mut T = res.as_mut_ptr().cast();
+
+        // SAFETY: Ranges 0..I and I..N are moved to 0..I and I + 1..M, element is written to I
+        unsafe {
+            ptr::copy_nonoverlapping(ptr, res_ptr, I);
+            ptr::copy_nonoverlapping(ptr.add(I), res_ptr.add(I + 1), N - I);
+
+            res_ptr.add(I).write(el);
+
+            res.assume_init()
+        }
+    }
+}
+
+/// Concatenates 2 owned arrays into one.
+/// 
+/// Same as calling [JoinOwned::join_owned] on a pair of arrays.
+/// ```
+/// use split_owned::{SplitOwned, concat_owned};
+/// 
+/// let arr: [String; 4] = ["a", "b", "c", "d"].map(String::from);
+/// 
+/// let (arr1, arr2) = arr.split_owned::<1, 3>();
+/// 
+/// let arr: [String; 4] = concat_owned(arr2, arr1);
+/// 
+/// assert_eq!(arr, ["b", "c", "d", "a"]);
+/// ```
+pub fn concat_owned<T, const K: usize, const L: usize, const N: usize>(arr_k: [T; K], arr_l: [T; L]) -> [T; N] {
+    (arr_k, arr_l).join_owned()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::SplitOwned;
+
+    use std::boxed::Box;
+
+    #[test]
+    fn join_easy() {
+        let arr1: [f64; 3] = [0., 1., 2.];
+        let arr2: [f64; 2] = [3., 4.];
+
+        let arr: [f64; 5] = (arr1, arr2).join_owned();
+
+        assert_eq!(arr, [0., 1., 2., 3., 4.]);
+    }
+
+    #[test]
+    fn join_zero() {
+        let arr: [f64; 3] = (<[f64; 0]>::default(), [0., 1., 2.]).join_owned();
+        assert_eq!(arr, [0., 1., 2.]);
+
+        let arr: [f64; 3] = concat_owned(arr, []);
+        assert_eq!(arr, [0., 1., 2.]);
+    }
+
+    #[test]
+    fn split_join_roundtrip() {
+        let arr: [Box<u32>; 9] = std::array::from_fn(|n| Box::new(n as u32));
+
+        let (arr1, arr2, arr3) = arr.split3_owned::<4, 3, 2>();
+        let arr: [Box<u32>; 9] = (arr1, arr2, arr3).join_owned();
+
+        assert_eq!(arr, std::array::from_fn(|n| Box::new(n as u32)));
+    }
+
+    #[test]
+    fn interleave_easy() {
+        let arr: [f64; 6] = ([0., 2., 4.], [1., 3., 5.]).interleave_owned();
+        assert_eq!(arr, [0., 1., 2., 3., 4., 5.]);
+
+        let arr: [f64; 6] = [[0., 3.], [1., 4.], [2., 5.]].interleave_owned();
+        assert_eq!(arr, [0., 1., 2., 3., 4., 5.]);
+
+        let arr: [f64; 0] = <[[f64; 3]; 0]>::default().interleave_owned();
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn deinterleave_interleave_roundtrip() {
+        let arr: [Box<u32>; 12] = std::array::from_fn(|n| Box::new(n as u32));
+
+        let lanes = arr.deinterleave_stride_owned::<4, 3>();
+        let arr: [Box<u32>; 12] = lanes.interleave_owned();
+
+        assert_eq!(arr, std::array::from_fn(|n| Box::new(n as u32)));
+    }
+
+    #[test]
+    fn insert_non_clone() {
+        let arr: [Box<u32>; 0] = [];
+
+        let arr = arr.insert_owned::<0, 1>(Box::new(1));
+        let arr = arr.insert_owned::<0, 2>(Box::new(0));
+        let arr = arr.insert_owned::<2, 3>(Box::new(3));
+        let arr = arr.insert_owned::<2, 4>(Box::new(2));
+
+        assert_eq!(arr, [0, 1, 2, 3].map(Box::new));
+    }
+
+    #[test]
+    fn extract_insert_roundtrip() {
+        let arr: [Box<u32>; 5] = std::array::from_fn(|n| Box::new(n as u32));
+
+        let (el, rest) = arr.extract_owned::<3, 4>();
+        let arr = rest.insert_owned::<3, 5>(el);
+
+        assert_eq!(arr, std::array::from_fn(|n| Box::new(n as u32)));
+    }
+}
@@ -1,6 +1,8 @@
 //! ### This crate provides simple way to split array in 2 owned arrays with compile-time bounds checks.
 //! 
 //! - Works with `Non-Copy` & `Non-Clone` types
+//! - `no_std` compatible. `Box` extensions require `alloc` feature, which is enabled by default through `std` feature
+//! - `nightly` feature provides `split_at_owned::<K>()`, which infers length of the second array from `N - K`
 //! 
 //! Common usage:
 //! ```
@@ -51,11 +53,88 @@
 //! assert_eq!(arr2, [Num(3.), Num(4.), Num(5.), Num(6.)]);
 //! ```
 
-use std::mem::MaybeUninit;
+#![no_std]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 
-/// Extention trait which provides [SplitOwned::split_owned] function.
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+mod join;
+pub use join::{JoinOwned, InterleaveOwned, InsertOwned, concat_owned};
+
+pub mod array_vec;
+pub use array_vec::ArrayVec;
+
+mod partition;
+pub use partition::PartitionOwned;
+
+mod iter;
+pub use iter::{IntoOwnedChunks, OwnedChunks, CollectSplit};
+
+mod borrowed;
+pub use borrowed::{SplitRef, SplitMut};
+
+mod zip;
+pub use zip::{UnzipOwned, zip_owned, zip3_owned, zip4_owned};
+
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+pub use boxed::SplitBoxed;
+
+mod error;
+pub use error::{LengthMismatch, CollectSplitError};
+
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+pub use vec::{TrySplitOwned, SplitOffArray, IntoArrayChunks};
+
+#[cfg(feature = "nightly")]
+mod nightly;
+#[cfg(feature = "nightly")]
+pub use nightly::SplitAtOwned;
+
+/// Extention trait which provides functions to split array into owned parts:
+/// - [SplitOwned::split_owned] and its higher arity versions [SplitOwned::split3_owned] & [SplitOwned::split4_owned]
+/// - [SplitOwned::rsplit_owned] to split array from the end
+/// - [SplitOwned::extract_range_owned] to move sub-array out of array together with its prefix and suffix
+/// - [SplitOwned::chunks_owned] to split array into chunks of equal length
+///   and [SplitOwned::chunks_owned_with_remainder] to keep the ragged tail as a separate array
+/// - [SplitOwned::deinterleave_owned] and [SplitOwned::deinterleave_stride_owned] to split interleaved array into lanes
+/// - [SplitOwned::extract_owned] to move single element out of array
+///   and [SplitOwned::split_first_owned] & [SplitOwned::split_last_owned] to move out first or last element
 pub trait SplitOwned<T> {
     fn split_owned<const K: usize, const L: usize>(self) -> ([T; K], [T; L]);
+
+    fn rsplit_owned<const L: usize, const K: usize>(self) -> ([T; L], [T; K]);
+
+    fn split3_owned<const A: usize, const B: usize, const C: usize>(self) -> ([T; A], [T; B], [T; C]);
+
+    fn split4_owned<const A: usize, const B: usize, const C: usize, const D: usize>(self) -> ([T; A], [T; B], [T; C], [T; D]);
+
+    fn chunks_owned<const C: usize, const M: usize>(self) -> [[T; C]; M];
+
+    fn chunks_owned_with_remainder<const C: usize, const M: usize, const R: usize>(self) -> ([[T; C]; M], [T; R]);
+
+    fn deinterleave_owned<const F: usize>(self) -> ([T; F], [T; F]);
+
+    fn deinterleave_stride_owned<const F: usize, const S: usize>(self) -> [[T; F]; S];
+
+    fn extract_owned<const I: usize, const M: usize>(self) -> (T, [T; M]);
+
+    fn split_first_owned<const M: usize>(self) -> (T, [T; M]);
+
+    fn split_last_owned<const M: usize>(self) -> ([T; M], T);
+
+    fn extract_range_owned<const START: usize, const LEN: usize, const PRE: usize, const POST: usize>(self) -> ([T; PRE], [T; LEN], [T; POST]);
 }
 
 impl<T, const N: usize> SplitOwned<T> for [T; N] {
@@ -84,39 +163,426 @@ impl<T, const N: usize> SplitOwned<T> for [T; N] {
     /// assert_eq!(arr1, [0, 1, 2]);
     /// assert_eq!(arr2, [3, 4, 5, 6]);
     /// ```
-
     fn split_owned<const K: usize, const L: usize>(self) -> ([T; K], [T; L]) {
         
-        const { assert!(N == K + L, 
+        const { const_assert(N == K + L, 
             "Length of original array has to be equal to sum of lengths of resulting arrays N == K + L"
         )};
 
-        // Wrap each element of original array in MaybeUninit for ease of use
-        let mut arr: [MaybeUninit<T>; N] = self.map(|el| MaybeUninit::new(el));
+        // Original array is never dropped, its elements are moved out bitwise instead
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
 
-        let mut arr_k: [MaybeUninit<T>; K] = std::array::from_fn(|_| MaybeUninit::uninit());
-        let mut arr_l: [MaybeUninit<T>; L] = std::array::from_fn(|_| MaybeUninit::uninit());
+        // SAFETY: Ranges 0..K and K..N are disjoint and cover the whole array
+        let arr_k: [T; K] = unsafe { read_owned(ptr) };
+        let arr_l: [T; L] = unsafe { read_owned(ptr.add(K)) };
 
-        for i in 0..K {
-            std::mem::swap(&mut arr_k[i], &mut arr[i]);
-        }
-        for i in 0..L {
-            std::mem::swap(&mut arr_l[i], &mut arr[i + K]);
+        (arr_k, arr_l)
+    }
+
+    /// Splits array from the end. Length of the tail `L` goes first, tail is returned first.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (tail, rest) = arr.rsplit_owned::<4, _>();
+    /// 
+    /// assert_eq!(tail, [3, 4, 5, 6]);
+    /// assert_eq!(rest, [0, 1, 2]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to sum of lengths of resulting arrays N == K + L
+    /// let (tail, rest) = arr.rsplit_owned::<4, 2>();
+    /// ```
+    fn rsplit_owned<const L: usize, const K: usize>(self) -> ([T; L], [T; K]) {
+        let (arr_k, arr_l) = self.split_owned::<K, L>();
+        (arr_l, arr_k)
+    }
+
+    /// Splits array in 3 owned arrays.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let packet: [u8; 8] = [0xAA, 0x03, 1, 2, 3, 4, 0xFF, 0xFF];
+    /// 
+    /// let (header, payload, trailer) = packet.split3_owned::<2, 4, 2>();
+    /// 
+    /// assert_eq!(header, [0xAA, 0x03]);
+    /// assert_eq!(payload, [1, 2, 3, 4]);
+    /// assert_eq!(trailer, [0xFF, 0xFF]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to sum of lengths of resulting arrays N == A + B + C
+    /// let (arr1, arr2, arr3) = arr.split3_owned::<2, 2, 2>();
+    /// ```
+    fn split3_owned<const A: usize, const B: usize, const C: usize>(self) -> ([T; A], [T; B], [T; C]) {
+
+        const { const_assert(N == A + B + C, 
+            "Length of original array has to be equal to sum of lengths of resulting arrays N == A + B + C"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        // SAFETY: Ranges are disjoint and cover the whole array
+        let arr_a: [T; A] = unsafe { read_owned(ptr) };
+        let arr_b: [T; B] = unsafe { read_owned(ptr.add(A)) };
+        let arr_c: [T; C] = unsafe { read_owned(ptr.add(A + B)) };
+
+        (arr_a, arr_b, arr_c)
+    }
+
+    /// Splits array in 4 owned arrays.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (arr1, arr2, arr3, arr4) = arr.split4_owned::<1, 2, 3, 1>();
+    /// 
+    /// assert_eq!(arr1, [0]);
+    /// assert_eq!(arr2, [1, 2]);
+    /// assert_eq!(arr3, [3, 4, 5]);
+    /// assert_eq!(arr4, [6]);
+    /// ```
+    fn split4_owned<const A: usize, const B: usize, const C: usize, const D: usize>(self) -> ([T; A], [T; B], [T; C], [T; D]) {
+
+        const { const_assert(N == A + B + C + D, 
+            "Length of original array has to be equal to sum of lengths of resulting arrays N == A + B + C + D"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        // SAFETY: Ranges are disjoint and cover the whole array
+        let arr_a: [T; A] = unsafe { read_owned(ptr) };
+        let arr_b: [T; B] = unsafe { read_owned(ptr.add(A)) };
+        let arr_c: [T; C] = unsafe { read_owned(ptr.add(A + B)) };
+        let arr_d: [T; D] = unsafe { read_owned(ptr.add(A + B + C)) };
+
+        (arr_a, arr_b, arr_c, arr_d)
+    }
+
+    /// Splits array in `M` owned chunks of length `C`.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 6] = [0, 1, 2, 3, 4, 5];
+    /// 
+    /// let chunks = arr.chunks_owned::<2, 3>();
+    /// 
+    /// assert_eq!(chunks, [[0, 1], [2, 3], [4, 5]]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to product of chunk length and number of chunks N == C * M
+    /// let chunks = arr.chunks_owned::<2, 3>();
+    /// ```
+    fn chunks_owned<const C: usize, const M: usize>(self) -> [[T; C]; M] {
+
+        const { const_assert(N == C * M, 
+            "Length of original array has to be equal to product of chunk length and number of chunks N == C * M"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const [T; C] = arr.as_ptr().cast();
+
+        // SAFETY: `[[T; C]; M]` has the same layout as `[T; N]` because N == C * M
+        unsafe { read_owned(ptr) }
+    }
+
+    /// Splits array in `M` owned chunks of length `C` and remainder of length `R`.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (chunks, rem) = arr.chunks_owned_with_remainder::<2, 3, 1>();
+    /// 
+    /// assert_eq!(chunks, [[0, 1], [2, 3], [4, 5]]);
+    /// assert_eq!(rem, [6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to total length of chunks and remainder N == C * M + R
+    /// let (chunks, rem) = arr.chunks_owned_with_remainder::<2, 3, 2>();
+    /// ```
+    fn chunks_owned_with_remainder<const C: usize, const M: usize, const R: usize>(self) -> ([[T; C]; M], [T; R]) {
+
+        const { const_assert(N == C * M + R, 
+            "Length of original array has to be equal to total length of chunks and remainder N == C * M + R"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        // SAFETY: Ranges 0..C * M and C * M..N are disjoint and cover the whole array,
+        // `[[T; C]; M]` has the same layout as `[T; C * M]`
+        let chunks: [[T; C]; M] = unsafe { read_owned(ptr.cast::<[T; C]>()) };
+        let rem: [T; R] = unsafe { read_owned(ptr.add(C * M)) };
+
+        (chunks, rem)
+    }
+
+    /// Splits array in 2 owned arrays of elements with even and odd indices.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let stereo: [f32; 6] = [0.1, -0.1, 0.2, -0.2, 0.3, -0.3];
+    /// 
+    /// let (left, right) = stereo.deinterleave_owned::<3>();
+    /// 
+    /// assert_eq!(left, [0.1, 0.2, 0.3]);
+    /// assert_eq!(right, [-0.1, -0.2, -0.3]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to twice the length of resulting arrays N == 2 * F
+    /// let (even, odd) = arr.deinterleave_owned::<3>();
+    /// ```
+    fn deinterleave_owned<const F: usize>(self) -> ([T; F], [T; F]) {
+
+        const { const_assert(N == 2 * F, 
+            "Length of original array has to be equal to twice the length of resulting arrays N == 2 * F"
+        )};
+
+        let [even, odd] = self.deinterleave_stride_owned::<F, 2>();
+        (even, odd)
+    }
+
+    /// Splits array in `S` owned lanes of length `F`, element `i` goes to lane `i % S`.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let rgb: [u8; 9] = [255, 0, 0, 0, 255, 0, 0, 0, 255];
+    /// 
+    /// let [r, g, b] = rgb.deinterleave_stride_owned::<3, 3>();
+    /// 
+    /// assert_eq!(r, [255, 0, 0]);
+    /// assert_eq!(g, [0, 255, 0]);
+    /// assert_eq!(b, [0, 0, 255]);
+    /// ```
+    fn deinterleave_stride_owned<const F: usize, const S: usize>(self) -> [[T; F]; S] {
+
+        const { const_assert(N == F * S, 
+            "Length of original array has to be equal to product of lane length and stride N == F * S"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        // SAFETY: Every index f * S + s for f < F & s < S is read exactly once and N == F * S
+        core::array::from_fn(|s| core::array::from_fn(|f| unsafe { ptr.add(f * S + s).read() }))
+    }
+
+    /// Moves element at index `I` out of array, returns it with the array of remaining `M` elements.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (el, rest) = arr.extract_owned::<2, 6>();
+    /// 
+    /// assert_eq!(el, 2);
+    /// assert_eq!(rest, [0, 1, 3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Index of extracted element has to be within the array I < N
+    /// let (el, rest) = arr.extract_owned::<7, 6>();
+    /// ```
+    fn extract_owned<const I: usize, const M: usize>(self) -> (T, [T; M]) {
+
+        const { const_assert(I < N, 
+            "Index of extracted element has to be within the array I < N"
+        )};
+        const { const_assert(M + 1 == N, 
+            "Length of resulting array has to be one less than length of original array M == N - 1"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        let mut rest: MaybeUninit<[T; M]> = MaybeUninit::uninit();
+        let rest_ptr: *mut T = rest.as_mut_ptr().cast();
+
+        // SAFETY: Element I is read once, ranges 0..I and I + 1..N are moved to 0..I and I..M
+        unsafe {
+            let el: T = ptr.add(I).read();
+
+            ptr::copy_nonoverlapping(ptr, rest_ptr, I);
+            ptr::copy_nonoverlapping(ptr.add(I + 1), rest_ptr.add(I), M - I);
+
+            (el, rest.assume_init())
         }
+    }
 
-        // SAFETY: Both arrays are initialized with elements from initial array
-        let arr_k: [T; K] = arr_k.map(|el: MaybeUninit<T> | unsafe { el.assume_init() });
-        let arr_l: [T; L] = arr_l.map(|el: MaybeUninit<T> | unsafe { el.assume_init() });
+    /// Moves first element out of array, returns it with the array of remaining `M` elements.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 4] = [0, 1, 2, 3];
+    /// 
+    /// let (first, rest) = arr.split_first_owned::<3>();
+    /// 
+    /// assert_eq!(first, 0);
+    /// assert_eq!(rest, [1, 2, 3]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 0] = [];
+    /// 
+    /// // Compile error: 
+    /// // Length of resulting array has to be one less than length of original array M == N - 1
+    /// let (first, rest) = arr.split_first_owned::<0>();
+    /// ```
+    fn split_first_owned<const M: usize>(self) -> (T, [T; M]) {
 
-        (arr_k, arr_l)
+        const { const_assert(M + 1 == N, 
+            "Length of resulting array has to be one less than length of original array M == N - 1"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        // SAFETY: Element 0 and range 1..N are disjoint and cover the whole array
+        unsafe { (ptr.read(), read_owned(ptr.add(1))) }
+    }
+
+    /// Moves last element out of array, returns it with the array of remaining `M` elements.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let arr: [i32; 4] = [0, 1, 2, 3];
+    /// 
+    /// let (rest, last) = arr.split_last_owned::<3>();
+    /// 
+    /// assert_eq!(rest, [0, 1, 2]);
+    /// assert_eq!(last, 3);
+    /// ```
+    fn split_last_owned<const M: usize>(self) -> ([T; M], T) {
+
+        const { const_assert(M + 1 == N, 
+            "Length of resulting array has to be one less than length of original array M == N - 1"
+        )};
+
+        let arr = ManuallyDrop::new(self);
+        let ptr: *const T = arr.as_ptr();
+
+        // SAFETY: Range 0..M and element M are disjoint and cover the whole array
+        unsafe { (read_owned(ptr), ptr.add(M).read()) }
+    }
+
+    /// Moves range `START..START + LEN` out of array, returns it with arrays of elements before and after it.
+    /// ```
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let frame: [u8; 8] = [0xAA, 0x03, 1, 2, 3, 4, 0xFF, 0xFF];
+    /// 
+    /// let (header, payload, trailer) = frame.extract_range_owned::<2, 4, 2, 2>();
+    /// 
+    /// assert_eq!(header, [0xAA, 0x03]);
+    /// assert_eq!(payload, [1, 2, 3, 4]);
+    /// assert_eq!(trailer, [0xFF, 0xFF]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::SplitOwned;
+    /// 
+    /// let frame: [u8; 8] = [0xAA, 0x03, 1, 2, 3, 4, 0xFF, 0xFF];
+    /// 
+    /// // Compile error: 
+    /// // Range has to end within the array START + LEN <= N
+    /// let (header, payload, trailer) = frame.extract_range_owned::<6, 4, 6, 0>();
+    /// ```
+    fn extract_range_owned<const START: usize, const LEN: usize, const PRE: usize, const POST: usize>(self) -> ([T; PRE], [T; LEN], [T; POST]) {
+
+        const { const_assert(START + LEN <= N, 
+            "Range has to end within the array START + LEN <= N"
+        )};
+        const { const_assert(PRE == START, 
+            "Length of prefix array has to be equal to start of the range PRE == START"
+        )};
+        const { const_assert(POST + START + LEN == N, 
+            "Length of suffix array has to be equal to number of elements after the range POST == N - START - LEN"
+        )};
+
+        self.split3_owned::<PRE, LEN, POST>()
     }
 }
 
+/// Compile-time length check used as `const { const_assert(N == K + L, "...") }`.
+/// 
+/// Unlike plain `assert!` inside `const` block it also compiles with `generic_const_exprs`,
+/// which does not allow control flow directly in generic constants.
+const fn const_assert(cond: bool, msg: &str) {
+    if !cond {
+        panic!("{}", msg)
+    }
+}
+
+/// Moves `K` elements starting at `ptr` into new owned array.
+/// 
+/// # Safety
+/// Elements `ptr..ptr + K` have to be initialized and valid for reads.
+/// They are moved bitwise, so they must not be read again or dropped after this call.
+unsafe fn read_owned<T, const K: usize>(ptr: *const T) -> [T; K] {
+    // SAFETY: `[T; K]` has the same alignment as `T`, validity is guaranteed by the caller
+    unsafe { ptr.cast::<[T; K]>().read() }
+}
+
+/// Moves elements of `arr_k` to `ptr..ptr + K`.
+/// 
+/// # Safety
+/// Elements `ptr..ptr + K` have to be valid for writes.
+/// Elements previously stored there are overwritten without being dropped.
+unsafe fn write_owned<T, const K: usize>(ptr: *mut T, arr_k: [T; K]) {
+    // SAFETY: `[T; K]` has the same alignment as `T`, validity is guaranteed by the caller
+    unsafe { ptr.cast::<[T; K]>().write(arr_k) }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
+    use std::string::String;
+
     #[test]
     fn split_easy() {
 
@@ -153,4 +619,161 @@ mod tests {
 
         assert_eq!(*arr1[0], 0.);
     }
+
+    #[test]
+    fn rsplit_easy() {
+        let arr: [f64; 10] = std::array::from_fn(|n| n as f64);
+
+        let (tail, rest) = arr.rsplit_owned::<3, 7>();
+
+        assert_eq!(tail, [7., 8., 9.]);
+        assert_eq!(rest, [0., 1., 2., 3., 4., 5., 6.]);
+
+        let (tail, rest): ([f64; 0], [f64; 10]) = arr.rsplit_owned();
+
+        assert_eq!(tail, []);
+        assert_eq!(rest, arr);
+    }
+
+    #[test]
+    fn deinterleave_non_clone() {
+        #[derive(Debug, PartialEq)]
+        struct Frame(u32);
+
+        let arr: [Frame; 8] = std::array::from_fn(|n| Frame(n as u32));
+
+        let (even, odd) = arr.deinterleave_owned::<4>();
+
+        assert_eq!(even, [Frame(0), Frame(2), Frame(4), Frame(6)]);
+        assert_eq!(odd, [Frame(1), Frame(3), Frame(5), Frame(7)]);
+    }
+
+    #[test]
+    fn deinterleave_stride() {
+        let arr: [f64; 12] = std::array::from_fn(|n| n as f64);
+
+        let lanes = arr.deinterleave_stride_owned::<3, 4>();
+        assert_eq!(lanes, [[0., 4., 8.], [1., 5., 9.], [2., 6., 10.], [3., 7., 11.]]);
+
+        let lanes = arr.deinterleave_stride_owned::<12, 1>();
+        assert_eq!(lanes, [arr]);
+
+        let lanes = arr.deinterleave_stride_owned::<1, 12>();
+        assert_eq!(lanes, arr.map(|el| [el]));
+    }
+
+    #[test]
+    fn split3_easy() {
+        let arr: [f64; 10] = std::array::from_fn(|n| n as f64);
+
+        let (arr1, arr2, arr3) = arr.split3_owned::<2, 5, 3>();
+
+        assert_eq!(arr1, [0., 1.]);
+        assert_eq!(arr2, [2., 3., 4., 5., 6.]);
+        assert_eq!(arr3, [7., 8., 9.]);
+    }
+
+    #[test]
+    fn split4_non_clone() {
+        #[derive(Debug, PartialEq)]
+        struct Num(u32);
+
+        let arr: [Num; 6] = std::array::from_fn(|n| Num(n as u32));
+
+        let (arr1, arr2, arr3, arr4) = arr.split4_owned::<0, 2, 1, 3>();
+
+        assert_eq!(arr1, []);
+        assert_eq!(arr2, [Num(0), Num(1)]);
+        assert_eq!(arr3, [Num(2)]);
+        assert_eq!(arr4, [Num(3), Num(4), Num(5)]);
+    }
+
+    #[test]
+    fn chunks_easy() {
+        let arr: [f64; 1024] = std::array::from_fn(|n| n as f64);
+
+        let chunks = arr.chunks_owned::<64, 16>();
+
+        for (m, chunk) in chunks.iter().enumerate() {
+            assert_eq!(*chunk, std::array::from_fn(|c| (m * 64 + c) as f64));
+        }
+    }
+
+    #[test]
+    fn chunks_zero() {
+        let chunks: [[String; 0]; 5] = <[String; 0]>::default().chunks_owned();
+        assert_eq!(chunks, <[[String; 0]; 5]>::default());
+
+        let chunks: [[String; 3]; 0] = <[String; 0]>::default().chunks_owned();
+        assert!(chunks.is_empty());
+    }
+
+    #[test]
+    fn chunks_with_remainder() {
+        let arr: [f64; 10] = std::array::from_fn(|n| n as f64);
+
+        let (chunks, rem) = arr.chunks_owned_with_remainder::<3, 3, 1>();
+
+        assert_eq!(chunks, [[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]]);
+        assert_eq!(rem, [9.]);
+
+        let (chunks, rem) = arr.chunks_owned_with_remainder::<4, 0, 10>();
+
+        assert!(chunks.is_empty());
+        assert_eq!(rem, arr);
+    }
+
+
+    #[test]
+    fn extract_non_clone() {
+        let arr: [String; 4] = ["a", "b", "c", "d"].map(String::from);
+
+        let (el, rest) = arr.extract_owned::<0, 3>();
+        assert_eq!(el, "a");
+        assert_eq!(rest, ["b", "c", "d"]);
+
+        let (el, rest) = rest.extract_owned::<2, 2>();
+        assert_eq!(el, "d");
+        assert_eq!(rest, ["b", "c"]);
+
+        let (el, rest) = [String::from("e")].extract_owned::<0, 0>();
+        assert_eq!(el, "e");
+        assert_eq!(rest, <[String; 0]>::default());
+    }
+
+
+    #[test]
+    fn split_first_last() {
+        #[derive(Debug, PartialEq)]
+        enum Command { Push(u32), Pop }
+
+        let arr: [Command; 3] = [Command::Push(0), Command::Push(1), Command::Pop];
+
+        let (first, rest) = arr.split_first_owned::<2>();
+        assert_eq!(first, Command::Push(0));
+
+        let (rest, last) = rest.split_last_owned::<1>();
+        assert_eq!(rest, [Command::Push(1)]);
+        assert_eq!(last, Command::Pop);
+
+        let (first, rest) = rest.split_first_owned::<0>();
+        assert_eq!(first, Command::Push(1));
+        assert_eq!(rest, []);
+    }
+
+
+    #[test]
+    fn extract_range() {
+        let arr: [f64; 6] = std::array::from_fn(|n| n as f64);
+
+        let (pre, mid, post) = arr.extract_range_owned::<1, 3, 1, 2>();
+        assert_eq!(pre, [0.]);
+        assert_eq!(mid, [1., 2., 3.]);
+        assert_eq!(post, [4., 5.]);
+
+        let (pre, mid, post) = arr.extract_range_owned::<6, 0, 6, 0>();
+        assert_eq!(pre, arr);
+        assert_eq!(mid, []);
+        assert_eq!(post, []);
+    }
 }
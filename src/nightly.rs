@@ -0,0 +1,70 @@
+use crate::SplitOwned;
+
+/// Extention trait which provides [SplitAtOwned::split_at_owned] function.
+/// 
+/// Requires `nightly` feature, which uses unstable `generic_const_exprs`
+/// to infer length of the second array from `N - K`.
+pub trait SplitAtOwned<T, const N: usize> {
+    fn split_at_owned<const K: usize>(self) -> ([T; K], [T; N - K]) where [(); N - K]:;
+}
+
+impl<T, const N: usize> SplitAtOwned<T, N> for [T; N] {
+
+    /// Only the split point has to be specified:
+    /// ```
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    /// use split_owned::SplitAtOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (arr1, arr2) = arr.split_at_owned::<3>();
+    /// 
+    /// assert_eq!(arr1, [0, 1, 2]);
+    /// assert_eq!(arr2, [3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    /// use split_owned::SplitAtOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Split point has to be within the array K <= N
+    /// let (arr1, arr2) = arr.split_at_owned::<8>();
+    /// ```
+    fn split_at_owned<const K: usize>(self) -> ([T; K], [T; N - K]) where [(); N - K]: {
+        self.split_owned::<K, { N - K }>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn split_at_easy() {
+        let arr: [f64; 6] = std::array::from_fn(|n| n as f64);
+
+        let (arr1, arr2) = arr.split_at_owned::<2>();
+
+        assert_eq!(arr1, [0., 1.]);
+        assert_eq!(arr2, [2., 3., 4., 5.]);
+    }
+
+    #[test]
+    fn split_at_zero() {
+        let arr: [f64; 3] = std::array::from_fn(|n| n as f64);
+
+        let (arr1, arr2) = arr.split_at_owned::<0>();
+        assert_eq!(arr1, []);
+        assert_eq!(arr2, [0., 1., 2.]);
+
+        let (arr1, arr2) = arr.split_at_owned::<3>();
+        assert_eq!(arr1, [0., 1., 2.]);
+        assert_eq!(arr2, []);
+    }
+}
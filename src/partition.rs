@@ -0,0 +1,186 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+use crate::{ArrayVec, const_assert, read_owned};
+
+/// Extention trait which provides [PartitionOwned::partition_owned] & [PartitionOwned::try_partition_exact] functions.
+/// 
+/// Unlike [SplitOwned](crate::SplitOwned) arrays are split by predicate, so sizes of the parts are known only at runtime.
+pub trait PartitionOwned<T, const N: usize> {
+    fn partition_owned<F: FnMut(&T) -> bool>(self, pred: F) -> (ArrayVec<T, N>, ArrayVec<T, N>);
+
+    fn try_partition_exact<const K: usize, const L: usize>(self, pred: impl FnMut(&T) -> bool) -> Result<([T; K], [T; L]), [T; N]>;
+}
+
+impl<T, const N: usize> PartitionOwned<T, N> for [T; N] {
+
+    /// Elements for which predicate returns `true` go to the first vector, the rest go to the second one.
+    /// Order of elements is preserved.
+    /// 
+    /// If predicate panics all elements are dropped.
+    /// ```
+    /// use split_owned::PartitionOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (even, odd) = arr.partition_owned(|el| el % 2 == 0);
+    /// 
+    /// assert_eq!(even, [0, 2, 4, 6]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// ```
+    fn partition_owned<F: FnMut(&T) -> bool>(self, mut pred: F) -> (ArrayVec<T, N>, ArrayVec<T, N>) {
+
+        let mut left: ArrayVec<T, N> = ArrayVec::new();
+        let mut right: ArrayVec<T, N> = ArrayVec::new();
+
+        for el in self {
+            if pred(&el) {
+                left.push(el);
+            } else {
+                right.push(el);
+            }
+        }
+        (left, right)
+    }
+
+    /// Splits array by predicate which is expected to match exactly `K` elements.
+    /// Order of elements is preserved.
+    /// 
+    /// Returns original array intact if number of matched elements is not `K`.
+    /// ```
+    /// use split_owned::PartitionOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (even, odd) = arr.try_partition_exact::<4, 3>(|el| el % 2 == 0).unwrap();
+    /// 
+    /// assert_eq!(even, [0, 2, 4, 6]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// 
+    /// let arr = arr.try_partition_exact::<3, 4>(|el| el % 2 == 0).unwrap_err();
+    /// 
+    /// assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::PartitionOwned;
+    /// 
+    /// let arr: [i32; 7] = [0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of original array has to be equal to sum of lengths of resulting arrays N == K + L
+    /// let result = arr.try_partition_exact::<4, 4>(|el| el % 2 == 0);
+    /// ```
+    fn try_partition_exact<const K: usize, const L: usize>(self, mut pred: impl FnMut(&T) -> bool) -> Result<([T; K], [T; L]), [T; N]> {
+
+        const { const_assert(N == K + L, 
+            "Length of original array has to be equal to sum of lengths of resulting arrays N == K + L"
+        )};
+
+        // Predicate is evaluated before anything is moved, so panics & mismatches leave the array intact
+        let matches: [bool; N] = core::array::from_fn(|i| pred(&self[i]));
+
+        if matches.iter().filter(|m| **m).count() != K {
+            return Err(self);
+        }
+
+        let arr = ManuallyDrop::new(self);
+
+        let mut arr_k: [MaybeUninit<T>; K] = [const { MaybeUninit::uninit() }; K];
+        let mut arr_l: [MaybeUninit<T>; L] = [const { MaybeUninit::uninit() }; L];
+
+        let (mut k, mut l) = (0, 0);
+
+        for (i, matched) in matches.into_iter().enumerate() {
+            // SAFETY: Every element is read exactly once, original array is never dropped
+            let el: T = unsafe { ptr::read(&arr[i]) };
+
+            if matched {
+                arr_k[k].write(el);
+                k += 1;
+            } else {
+                arr_l[l].write(el);
+                l += 1;
+            }
+        }
+
+        // SAFETY: Exactly K elements matched and the other L did not, so both arrays are fully initialized
+        unsafe { Ok((read_owned(arr_k.as_ptr().cast()), read_owned(arr_l.as_ptr().cast()))) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+    use std::string::String;
+
+    #[test]
+    fn partition_non_clone() {
+        let arr: [String; 4] = ["a", "bb", "cc", "d"].map(String::from);
+
+        let (short, long) = arr.partition_owned(|el| el.len() == 1);
+
+        assert_eq!(short, ["a", "d"]);
+        assert_eq!(long, ["bb", "cc"]);
+
+        let (all, none) = [0., 1.].partition_owned(|_| true);
+
+        assert_eq!(all, [0., 1.]);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn partition_panic_drops_all() {
+        let rc = Rc::new(());
+
+        let arr: [Rc<()>; 6] = std::array::from_fn(|_| Rc::clone(&rc));
+
+        let mut calls = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| arr.partition_owned(|_| {
+            calls += 1;
+            if calls == 4 {
+                panic!("predicate panicked");
+            }
+            calls % 2 == 0
+        })));
+
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn partition_exact() {
+        let arr: [String; 5] = ["a", "bb", "c", "dd", "e"].map(String::from);
+
+        let arr = arr.try_partition_exact::<2, 3>(|el| el.len() == 1).unwrap_err();
+        assert_eq!(arr, ["a", "bb", "c", "dd", "e"]);
+
+        let arr = arr.try_partition_exact::<4, 1>(|el| el.len() == 1).unwrap_err();
+        assert_eq!(arr, ["a", "bb", "c", "dd", "e"]);
+
+        let (short, long) = arr.try_partition_exact::<3, 2>(|el| el.len() == 1).unwrap();
+        assert_eq!(short, ["a", "c", "e"]);
+        assert_eq!(long, ["bb", "dd"]);
+    }
+
+    #[test]
+    fn partition_exact_drops_once() {
+        let rc = Rc::new(());
+
+        let arr: [Rc<()>; 4] = std::array::from_fn(|_| Rc::clone(&rc));
+
+        let arr = arr.try_partition_exact::<1, 3>(|_| false).unwrap_err();
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        let mut flip = false;
+        let (arr1, arr2) = arr.try_partition_exact::<2, 2>(|_| { flip = !flip; flip }).unwrap();
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        drop((arr1, arr2));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
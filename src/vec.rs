@@ -0,0 +1,431 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+use crate::{LengthMismatch, const_assert, read_owned};
+
+/// Extention trait which provides [TrySplitOwned::try_split_owned] function
+/// for containers which length is known only at runtime.
+/// 
+/// Implemented for [`Vec<T>`] and [`Box<[T]>`].
+pub trait TrySplitOwned<T>: Sized {
+    fn try_split_owned<const K: usize, const L: usize>(self) -> Result<([T; K], [T; L]), LengthMismatch<Self>>;
+}
+
+impl<T> TrySplitOwned<T> for Vec<T> {
+
+    /// Returns original vector if its length is not `K + L`.
+    /// ```
+    /// use split_owned::TrySplitOwned;
+    /// 
+    /// let vec: Vec<i32> = vec![0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (arr1, arr2) = vec.try_split_owned::<3, 4>().unwrap();
+    /// 
+    /// assert_eq!(arr1, [0, 1, 2]);
+    /// assert_eq!(arr2, [3, 4, 5, 6]);
+    /// 
+    /// let vec: Vec<i32> = vec![0, 1, 2];
+    /// 
+    /// let err = vec.try_split_owned::<3, 4>().unwrap_err();
+    /// 
+    /// assert_eq!((err.expected(), err.actual()), (7, 3));
+    /// assert_eq!(err.into_inner(), [0, 1, 2]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::TrySplitOwned;
+    /// 
+    /// struct Token;
+    /// 
+    /// impl Drop for Token {
+    ///     fn drop(&mut self) {}
+    /// }
+    /// 
+    /// // Compile error: 
+    /// // Sum of lengths of resulting arrays has to fit in usize K + L <= usize::MAX
+    /// let result = Vec::<Token>::new().try_split_owned::<{ usize::MAX }, 1>();
+    /// ```
+    fn try_split_owned<const K: usize, const L: usize>(self) -> Result<([T; K], [T; L]), LengthMismatch<Self>> {
+
+        const { const_assert(K <= usize::MAX - L, 
+            "Sum of lengths of resulting arrays has to fit in usize K + L <= usize::MAX"
+        )};
+
+        let expected: usize = K + L;
+
+        if self.len() != expected {
+            let len = self.len();
+            return Err(LengthMismatch::new(expected, len, self));
+        }
+
+        // SAFETY: Length of the vector is K + L
+        Ok(unsafe { split_vec_unchecked(self) })
+    }
+}
+
+impl<T> TrySplitOwned<T> for Box<[T]> {
+
+    /// Returns original box if its length is not `K + L`.
+    /// ```
+    /// use split_owned::TrySplitOwned;
+    /// 
+    /// let boxed: Box<[i32]> = Box::new([0, 1, 2, 3, 4, 5, 6]);
+    /// 
+    /// let (arr1, arr2) = boxed.try_split_owned::<3, 4>().unwrap();
+    /// 
+    /// assert_eq!(arr1, [0, 1, 2]);
+    /// assert_eq!(arr2, [3, 4, 5, 6]);
+    /// ```
+    fn try_split_owned<const K: usize, const L: usize>(self) -> Result<([T; K], [T; L]), LengthMismatch<Self>> {
+
+        const { const_assert(K <= usize::MAX - L, 
+            "Sum of lengths of resulting arrays has to fit in usize K + L <= usize::MAX"
+        )};
+
+        let expected: usize = K + L;
+
+        if self.len() != expected {
+            let len = self.len();
+            return Err(LengthMismatch::new(expected, len, self));
+        }
+
+        // SAFETY: Length of the boxed slice is K + L
+        Ok(unsafe { split_vec_unchecked(self.into_vec()) })
+    }
+}
+
+/// Extention trait which provides [SplitOffArray::split_off_array] function
+/// for growable containers.
+/// 
+/// Implemented for [`Vec<T>`] and [`VecDeque<T>`].
+pub trait SplitOffArray<T>: Sized {
+    fn split_off_array<const K: usize>(self) -> Option<([T; K], Self)>;
+}
+
+impl<T> SplitOffArray<T> for Vec<T> {
+
+    /// Moves first `K` elements into owned array, remaining elements are shifted to the front
+    /// and keep the original allocation.
+    /// 
+    /// Returns `None` if vector has less than `K` elements.
+    /// ```
+    /// use split_owned::SplitOffArray;
+    /// 
+    /// let vec: Vec<i32> = vec![0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (header, rest) = vec.split_off_array::<3>().unwrap();
+    /// 
+    /// assert_eq!(header, [0, 1, 2]);
+    /// assert_eq!(rest, [3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(rest.split_off_array::<5>(), None);
+    /// ```
+    fn split_off_array<const K: usize>(mut self) -> Option<([T; K], Self)> {
+
+        let len = self.len();
+        if len < K {
+            return None;
+        }
+
+        let ptr: *mut T = self.as_mut_ptr();
+
+        // SAFETY: Elements 0..K are moved out bitwise and then overwritten by elements K..len
+        unsafe {
+            let arr: [T; K] = read_owned(ptr);
+
+            ptr::copy(ptr.add(K), ptr, len - K);
+            self.set_len(len - K);
+
+            Some((arr, self))
+        }
+    }
+}
+
+impl<T> SplitOffArray<T> for VecDeque<T> {
+
+    /// Moves first `K` elements into owned array, remaining elements keep the original allocation.
+    /// 
+    /// Returns `None` if deque has less than `K` elements.
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use split_owned::SplitOffArray;
+    /// 
+    /// let deque: VecDeque<i32> = VecDeque::from([0, 1, 2, 3, 4, 5, 6]);
+    /// 
+    /// let (header, rest) = deque.split_off_array::<3>().unwrap();
+    /// 
+    /// assert_eq!(header, [0, 1, 2]);
+    /// assert_eq!(rest, [3, 4, 5, 6]);
+    /// ```
+    fn split_off_array<const K: usize>(mut self) -> Option<([T; K], Self)> {
+
+        if self.len() < K {
+            return None;
+        }
+
+        let arr: [T; K] = core::array::from_fn(|_| self.pop_front().expect("deque has at least K elements"));
+
+        Some((arr, self))
+    }
+}
+
+/// Extention trait which provides [IntoArrayChunks::into_array_chunks] function.
+pub trait IntoArrayChunks<T> {
+    fn into_array_chunks<const K: usize>(self) -> (Vec<[T; K]>, Vec<T>);
+}
+
+impl<T> IntoArrayChunks<T> for Vec<T> {
+
+    /// Converts vector into vector of chunks of length `K` and vector of remaining `len % K` elements.
+    /// 
+    /// Only remaining elements are moved, chunks reuse the original allocation.
+    /// If its capacity is not divisible by `K` it is shrunk first, which may reallocate it.
+    /// ```
+    /// use split_owned::IntoArrayChunks;
+    /// 
+    /// let vec: Vec<i32> = vec![0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// let (chunks, rem) = vec.into_array_chunks::<3>();
+    /// 
+    /// assert_eq!(chunks, [[0, 1, 2], [3, 4, 5]]);
+    /// assert_eq!(rem, [6]);
+    /// ```
+    /// Does not compile
+    /// ```compile_fail
+    /// use split_owned::IntoArrayChunks;
+    /// 
+    /// let vec: Vec<i32> = vec![0, 1, 2, 3, 4, 5, 6];
+    /// 
+    /// // Compile error: 
+    /// // Length of chunks has to be greater than zero K > 0
+    /// let (chunks, rem) = vec.into_array_chunks::<0>();
+    /// ```
+    // `is_multiple_of` is avoided as it would raise MSRV to 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    fn into_array_chunks<const K: usize>(mut self) -> (Vec<[T; K]>, Vec<T>) {
+
+        const { const_assert(K > 0, 
+            "Length of chunks has to be greater than zero K > 0"
+        )};
+
+        let rem: Vec<T> = self.split_off(self.len() - self.len() % K);
+
+        if mem::size_of::<T>() != 0 && self.capacity() % K != 0 {
+            self.shrink_to(self.capacity() - self.capacity() % K);
+        }
+
+        if mem::size_of::<T>() != 0 && self.capacity() % K == 0 {
+            let mut vec = ManuallyDrop::new(self);
+
+            // SAFETY: `[T; K]` has the same alignment as `T` and capacity in bytes is unchanged,
+            // length is divisible by K after the remainder was split off
+            let chunks: Vec<[T; K]> = unsafe {
+                Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len() / K, vec.capacity() / K)
+            };
+            return (chunks, rem);
+        }
+
+        // Only a safeguard: `shrink_to` is not guaranteed to produce exact capacity, though in practice it does.
+        // ZSTs always take this path, copying them is a no-op
+        let mut chunks: Vec<[T; K]> = Vec::with_capacity(self.len() / K);
+
+        // SAFETY: Elements are moved bitwise into reserved capacity, vector only frees its allocation when dropped
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), chunks.as_mut_ptr().cast::<T>(), self.len());
+
+            chunks.set_len(self.len() / K);
+            self.set_len(0);
+        }
+        (chunks, rem)
+    }
+}
+
+/// Moves elements of the vector into 2 owned arrays and frees its allocation.
+/// 
+/// # Safety
+/// Length of the vector has to be `K + L`.
+unsafe fn split_vec_unchecked<T, const K: usize, const L: usize>(mut vec: Vec<T>) -> ([T; K], [T; L]) {
+
+    let ptr: *const T = vec.as_ptr();
+
+    // SAFETY: Elements are moved out bitwise, vector only frees its allocation when dropped.
+    // Ranges 0..K and K..K + L are disjoint and cover the whole vector
+    unsafe {
+        vec.set_len(0);
+
+        (read_owned(ptr), read_owned(ptr.add(K)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use alloc::vec;
+    use std::rc::Rc;
+    use std::string::String;
+
+    #[test]
+    fn try_split_vec() {
+        let vec: Vec<String> = ["a", "b", "c"].map(String::from).to_vec();
+
+        let err = vec.try_split_owned::<2, 2>().unwrap_err();
+        assert_eq!(err.expected(), 4);
+        assert_eq!(err.actual(), 3);
+
+        let vec = err.into_inner();
+        assert_eq!(vec, ["a", "b", "c"]);
+
+        let (arr1, arr2) = vec.try_split_owned::<1, 2>().unwrap();
+        assert_eq!(arr1, ["a"]);
+        assert_eq!(arr2, ["b", "c"]);
+
+        let (arr1, arr2) = Vec::<String>::new().try_split_owned::<0, 0>().unwrap();
+        assert_eq!(arr1, <[String; 0]>::default());
+        assert_eq!(arr2, <[String; 0]>::default());
+    }
+
+    #[test]
+    fn try_split_zero_sized_non_copy() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug)]
+        struct Token;
+
+        impl Drop for Token {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let err = Vec::<Token>::new().try_split_owned::<{ usize::MAX - 1 }, 1>().unwrap_err();
+        assert_eq!(err.expected(), usize::MAX);
+        assert_eq!(err.actual(), 0);
+        drop(err);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+
+        let vec: Vec<Token> = vec![Token, Token, Token];
+
+        let vec = vec.try_split_owned::<2, 2>().unwrap_err().into_inner();
+        assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+
+        let boxed = vec.into_boxed_slice().try_split_owned::<0, 4>().unwrap_err().into_inner();
+        assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+
+        let (arr1, arr2) = boxed.try_split_owned::<1, 2>().unwrap();
+        drop((arr1, arr2));
+        assert_eq!(DROPS.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn try_split_boxed_slice() {
+        let rc = Rc::new(());
+
+        let boxed: Box<[Rc<()>]> = vec![Rc::clone(&rc); 5].into_boxed_slice();
+
+        let boxed = boxed.try_split_owned::<3, 3>().unwrap_err().into_inner();
+        assert_eq!(boxed.len(), 5);
+        assert_eq!(Rc::strong_count(&rc), 6);
+
+        let (arr1, arr2) = boxed.try_split_owned::<3, 2>().unwrap();
+        assert_eq!(Rc::strong_count(&rc), 6);
+
+        drop((arr1, arr2));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn split_off_array_keeps_allocation() {
+        let mut vec: Vec<String> = Vec::with_capacity(16);
+        vec.extend(["a", "b", "c", "d", "e"].map(String::from));
+
+        let ptr = vec.as_ptr();
+
+        let (arr, vec) = vec.split_off_array::<2>().unwrap();
+        assert_eq!(arr, ["a", "b"]);
+        assert_eq!(vec, ["c", "d", "e"]);
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec.capacity(), 16);
+
+        let (arr, vec) = vec.split_off_array::<3>().unwrap();
+        assert_eq!(arr, ["c", "d", "e"]);
+        assert!(vec.is_empty());
+
+        let (arr, vec) = vec.split_off_array::<0>().unwrap();
+        assert_eq!(arr, <[String; 0]>::default());
+        assert!(vec.split_off_array::<1>().is_none());
+    }
+
+    #[test]
+    fn split_off_array_deque() {
+        let rc = Rc::new(());
+
+        let mut deque: VecDeque<Rc<()>> = VecDeque::with_capacity(16);
+        deque.extend(vec![Rc::clone(&rc); 5]);
+
+        let (arr, deque) = deque.split_off_array::<2>().unwrap();
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.capacity(), 16);
+        assert_eq!(Rc::strong_count(&rc), 6);
+
+        drop(arr);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        assert!(deque.split_off_array::<4>().is_none());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn into_array_chunks_keeps_allocation() {
+        let mut vec: Vec<String> = Vec::with_capacity(12);
+        vec.extend(["a", "b", "c", "d", "e", "f", "g"].map(String::from));
+
+        let ptr = vec.as_ptr();
+
+        let (chunks, rem) = vec.into_array_chunks::<2>();
+
+        assert_eq!(chunks, [["a", "b"], ["c", "d"], ["e", "f"]]);
+        assert_eq!(rem, ["g"]);
+        assert_eq!(chunks.as_ptr().cast(), ptr);
+        assert_eq!(chunks.capacity(), 6);
+    }
+
+    #[test]
+    fn into_array_chunks_uneven_capacity() {
+        let rc = Rc::new(());
+
+        let mut vec: Vec<Rc<()>> = Vec::with_capacity(11);
+        vec.extend(vec![Rc::clone(&rc); 10]);
+
+        let (chunks, rem) = vec.into_array_chunks::<4>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(rem.len(), 2);
+        assert_eq!(Rc::strong_count(&rc), 11);
+
+        drop((chunks, rem));
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let (chunks, rem) = vec![(); 7].into_array_chunks::<3>();
+        assert_eq!(chunks, [[(); 3]; 2]);
+        assert_eq!(rem, [()]);
+
+        let (chunks, rem) = Vec::<u8>::new().into_array_chunks::<3>();
+        assert!(chunks.is_empty() && rem.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn length_mismatch_error() {
+        use std::string::ToString;
+
+        let err: Box<dyn std::error::Error> = vec![0; 3].try_split_owned::<1, 1>().unwrap_err().into();
+
+        assert_eq!(err.to_string(), "expected length 2, found 3");
+    }
+}
@@ -0,0 +1,238 @@
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+use crate::const_assert;
+
+/// Extention trait which provides [UnzipOwned::unzip_owned] function.
+/// 
+/// Implemented for arrays of 2, 3 and 4 element tuples.
+pub trait UnzipOwned {
+    type Output;
+
+    fn unzip_owned(self) -> Self::Output;
+}
+
+impl<A, B, const N: usize> UnzipOwned for [(A, B); N] {
+    type Output = ([A; N], [B; N]);
+
+    /// Common usage:
+    /// ```
+    /// use split_owned::UnzipOwned;
+    /// 
+    /// let arr: [(i32, char); 3] = [(0, 'a'), (1, 'b'), (2, 'c')];
+    /// 
+    /// let (nums, chars) = arr.unzip_owned();
+    /// 
+    /// assert_eq!(nums, [0, 1, 2]);
+    /// assert_eq!(chars, ['a', 'b', 'c']);
+    /// ```
+    fn unzip_owned(self) -> ([A; N], [B; N]) {
+
+        let arr = ManuallyDrop::new(self);
+
+        // SAFETY: Every component of every tuple is read exactly once
+        unsafe {(
+            core::array::from_fn(|i| ptr::read(&arr[i].0)),
+            core::array::from_fn(|i| ptr::read(&arr[i].1)),
+        )}
+    }
+}
+
+impl<A, B, C, const N: usize> UnzipOwned for [(A, B, C); N] {
+    type Output = ([A; N], [B; N], [C; N]);
+
+    /// ```
+    /// use split_owned::UnzipOwned;
+    /// 
+    /// let arr: [(i32, char, bool); 2] = [(0, 'a', true), (1, 'b', false)];
+    /// 
+    /// let (nums, chars, bools) = arr.unzip_owned();
+    /// 
+    /// assert_eq!(nums, [0, 1]);
+    /// assert_eq!(chars, ['a', 'b']);
+    /// assert_eq!(bools, [true, false]);
+    /// ```
+    fn unzip_owned(self) -> ([A; N], [B; N], [C; N]) {
+
+        let arr = ManuallyDrop::new(self);
+
+        // SAFETY: Every component of every tuple is read exactly once
+        unsafe {(
+            core::array::from_fn(|i| ptr::read(&arr[i].0)),
+            core::array::from_fn(|i| ptr::read(&arr[i].1)),
+            core::array::from_fn(|i| ptr::read(&arr[i].2)),
+        )}
+    }
+}
+
+impl<A, B, C, D, const N: usize> UnzipOwned for [(A, B, C, D); N] {
+    type Output = ([A; N], [B; N], [C; N], [D; N]);
+
+    /// ```
+    /// use split_owned::UnzipOwned;
+    /// 
+    /// let arr: [(i32, char, bool, f64); 2] = [(0, 'a', true, 0.5), (1, 'b', false, 1.5)];
+    /// 
+    /// let (nums, chars, bools, floats) = arr.unzip_owned();
+    /// 
+    /// assert_eq!(nums, [0, 1]);
+    /// assert_eq!(chars, ['a', 'b']);
+    /// assert_eq!(bools, [true, false]);
+    /// assert_eq!(floats, [0.5, 1.5]);
+    /// ```
+    fn unzip_owned(self) -> ([A; N], [B; N], [C; N], [D; N]) {
+
+        let arr = ManuallyDrop::new(self);
+
+        // SAFETY: Every component of every tuple is read exactly once
+        unsafe {(
+            core::array::from_fn(|i| ptr::read(&arr[i].0)),
+            core::array::from_fn(|i| ptr::read(&arr[i].1)),
+            core::array::from_fn(|i| ptr::read(&arr[i].2)),
+            core::array::from_fn(|i| ptr::read(&arr[i].3)),
+        )}
+    }
+}
+
+/// Zips 2 owned arrays into array of pairs, inverse of [UnzipOwned::unzip_owned].
+/// 
+/// Unlike [Iterator::zip] arrays of different lengths are rejected at compile time instead of being truncated.
+/// ```
+/// use split_owned::zip_owned;
+/// 
+/// let readings: [f64; 3] = [0.5, 0.7, 0.6];
+/// let timestamps: [u64; 3] = [100, 200, 300];
+/// 
+/// let arr = zip_owned(readings, timestamps);
+/// 
+/// assert_eq!(arr, [(0.5, 100), (0.7, 200), (0.6, 300)]);
+/// ```
+/// Does not compile
+/// ```compile_fail
+/// use split_owned::zip_owned;
+/// 
+/// // Compile error: 
+/// // Lengths of zipped arrays have to be equal N == M
+/// let arr = zip_owned([0.5, 0.7, 0.6], [100, 200]);
+/// ```
+pub fn zip_owned<A, B, const N: usize, const M: usize>(arr_a: [A; N], arr_b: [B; M]) -> [(A, B); N] {
+
+    const { const_assert(N == M, 
+        "Lengths of zipped arrays have to be equal N == M"
+    )};
+
+    let arr_a = ManuallyDrop::new(arr_a);
+    let arr_b = ManuallyDrop::new(arr_b);
+
+    // SAFETY: Every element of every array is read exactly once
+    core::array::from_fn(|i| unsafe {(
+        ptr::read(&arr_a[i]),
+        ptr::read(&arr_b[i]),
+    )})
+}
+
+/// Zips 3 owned arrays into array of tuples.
+/// ```
+/// use split_owned::zip3_owned;
+/// 
+/// let arr = zip3_owned([0, 1], ['a', 'b'], [true, false]);
+/// 
+/// assert_eq!(arr, [(0, 'a', true), (1, 'b', false)]);
+/// ```
+pub fn zip3_owned<A, B, C, const N: usize, const M: usize, const K: usize>(arr_a: [A; N], arr_b: [B; M], arr_c: [C; K]) -> [(A, B, C); N] {
+
+    const { const_assert((N == M) & (N == K), 
+        "Lengths of zipped arrays have to be equal N == M == K"
+    )};
+
+    let arr_a = ManuallyDrop::new(arr_a);
+    let arr_b = ManuallyDrop::new(arr_b);
+    let arr_c = ManuallyDrop::new(arr_c);
+
+    // SAFETY: Every element of every array is read exactly once
+    core::array::from_fn(|i| unsafe {(
+        ptr::read(&arr_a[i]),
+        ptr::read(&arr_b[i]),
+        ptr::read(&arr_c[i]),
+    )})
+}
+
+/// Zips 4 owned arrays into array of tuples.
+/// ```
+/// use split_owned::zip4_owned;
+/// 
+/// let arr = zip4_owned([0, 1], ['a', 'b'], [true, false], [0.5, 1.5]);
+/// 
+/// assert_eq!(arr, [(0, 'a', true, 0.5), (1, 'b', false, 1.5)]);
+/// ```
+pub fn zip4_owned<A, B, C, D, const N: usize, const M: usize, const K: usize, const L: usize>(arr_a: [A; N], arr_b: [B; M], arr_c: [C; K], arr_d: [D; L]) -> [(A, B, C, D); N] {
+
+    const { const_assert((N == M) & (N == K) & (N == L), 
+        "Lengths of zipped arrays have to be equal N == M == K == L"
+    )};
+
+    let arr_a = ManuallyDrop::new(arr_a);
+    let arr_b = ManuallyDrop::new(arr_b);
+    let arr_c = ManuallyDrop::new(arr_c);
+    let arr_d = ManuallyDrop::new(arr_d);
+
+    // SAFETY: Every element of every array is read exactly once
+    core::array::from_fn(|i| unsafe {(
+        ptr::read(&arr_a[i]),
+        ptr::read(&arr_b[i]),
+        ptr::read(&arr_c[i]),
+        ptr::read(&arr_d[i]),
+    )})
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::rc::Rc;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn unzip_non_clone() {
+        let arr: [(String, Rc<u32>); 4] = std::array::from_fn(|n| (n.to_string(), Rc::new(n as u32)));
+
+        let (strings, rcs) = arr.unzip_owned();
+
+        assert_eq!(strings, ["0", "1", "2", "3"]);
+        assert_eq!(rcs, [0, 1, 2, 3].map(Rc::new));
+        assert!(rcs.iter().all(|rc| Rc::strong_count(rc) == 1));
+    }
+
+    #[test]
+    fn unzip_zero() {
+        let arr: [(f64, u8, char); 0] = [];
+
+        let (floats, bytes, chars) = arr.unzip_owned();
+
+        assert_eq!(floats, []);
+        assert_eq!(bytes, []);
+        assert_eq!(chars, []);
+    }
+
+    #[test]
+    fn zip_unzip_roundtrip() {
+        let strings: [String; 3] = ["a", "b", "c"].map(String::from);
+        let rcs: [Rc<u32>; 3] = [0, 1, 2].map(Rc::new);
+
+        let arr = zip_owned(strings, rcs);
+        assert_eq!(arr[1], ("b".to_string(), Rc::new(1)));
+
+        let (strings, rcs) = arr.unzip_owned();
+        assert_eq!(strings, ["a", "b", "c"]);
+        assert!(rcs.iter().all(|rc| Rc::strong_count(rc) == 1));
+    }
+
+    #[test]
+    fn zip4_easy() {
+        let arr = zip4_owned([0., 1.], [2, 3], ['a', 'b'], [(), ()]);
+
+        assert_eq!(arr, [(0., 2, 'a', ()), (1., 3, 'b', ())]);
+        assert_eq!(zip3_owned::<u8, u8, u8, 0, 0, 0>([], [], []), []);
+    }
+}